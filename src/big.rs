@@ -0,0 +1,270 @@
+//! Limb-aware bit tricks over fixed-size multi-word integers.
+//!
+//! [`BigUint`] wraps a little-endian `[usize; LIMBS]` (limb `0` is the least
+//! significant word) so the delight routines can operate across a 256/512-bit
+//! value rather than a single machine word. The carry/borrow-sensitive tricks
+//! propagate through the limb array: a decrement borrows through higher limbs
+//! until a non-zero limb absorbs it, and the two's-complement negation used to
+//! isolate the lowest set bit inverts every limb before adding one with carry
+//! propagation.
+//!
+//! All-zero and all-ones inputs reproduce the single-word semantics documented
+//! on the crate-root routines.
+
+use core::ops::{BitAnd, BitOr, Not};
+
+/// A fixed-size little-endian unsigned integer stored as `LIMBS` machine words.
+///
+/// Limb `0` holds the least-significant bits; arithmetic wraps at the
+/// `LIMBS * usize::BITS`-bit boundary, matching the single-word routines.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BigUint<const LIMBS: usize>(pub [usize; LIMBS]);
+
+impl<const LIMBS: usize> BigUint<LIMBS> {
+    /// The all-zero value.
+    pub const ZERO: Self = Self([0; LIMBS]);
+
+    /// Wraps a little-endian array of limbs.
+    pub const fn new(limbs: [usize; LIMBS]) -> Self {
+        Self(limbs)
+    }
+
+    /// The value `1`, i.e. only the least-significant bit set.
+    pub const fn one() -> Self {
+        let mut limbs = [0usize; LIMBS];
+        if LIMBS > 0 {
+            limbs[0] = 1;
+        }
+        Self(limbs)
+    }
+
+    /// Wrapping (modular) addition with carry propagation across limbs.
+    /// The carry out of the most-significant limb is discarded.
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        let mut out = [0usize; LIMBS];
+        let mut carry = false;
+
+        for (o, (a, b)) in out.iter_mut().zip(self.0.iter().zip(rhs.0.iter())) {
+            let (s, c1) = a.overflowing_add(*b);
+            let (s, c2) = s.overflowing_add(carry as usize);
+            *o = s;
+            carry = c1 || c2;
+        }
+
+        Self(out)
+    }
+
+    /// Wrapping (modular) subtraction with borrow propagation across limbs.
+    /// The borrow out of the most-significant limb is discarded.
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        let mut out = [0usize; LIMBS];
+        let mut borrow = false;
+
+        for (o, (a, b)) in out.iter_mut().zip(self.0.iter().zip(rhs.0.iter())) {
+            let (d, b1) = a.overflowing_sub(*b);
+            let (d, b2) = d.overflowing_sub(borrow as usize);
+            *o = d;
+            borrow = b1 || b2;
+        }
+
+        Self(out)
+    }
+
+    /// Two's-complement negation: invert every limb, then add one with carry
+    /// propagation.
+    pub fn wrapping_neg(self) -> Self {
+        (!self).wrapping_add(Self::one())
+    }
+}
+
+impl<const LIMBS: usize> Not for BigUint<LIMBS> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        let mut out = [0usize; LIMBS];
+
+        for (o, a) in out.iter_mut().zip(self.0.iter()) {
+            *o = !*a;
+        }
+
+        Self(out)
+    }
+}
+
+impl<const LIMBS: usize> BitAnd for BigUint<LIMBS> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        let mut out = [0usize; LIMBS];
+
+        for (o, (a, b)) in out.iter_mut().zip(self.0.iter().zip(rhs.0.iter())) {
+            *o = a & b;
+        }
+
+        Self(out)
+    }
+}
+
+impl<const LIMBS: usize> BitOr for BigUint<LIMBS> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        let mut out = [0usize; LIMBS];
+
+        for (o, (a, b)) in out.iter_mut().zip(self.0.iter().zip(rhs.0.iter())) {
+            *o = a | b;
+        }
+
+        Self(out)
+    }
+}
+
+/// Converts the right-most 1 bit to a 0.
+/// Returns 0 if all bits are 0.
+pub fn binary_turn_off_rightmost_one<const LIMBS: usize>(x: BigUint<LIMBS>) -> BigUint<LIMBS> {
+    x & x.wrapping_sub(BigUint::one())
+}
+
+/// Converts the right-most 0 bit to a 1.
+/// Returns the all-ones value if all bits are 1.
+pub fn binary_turn_on_rightmost_zero<const LIMBS: usize>(x: BigUint<LIMBS>) -> BigUint<LIMBS> {
+    x | x.wrapping_add(BigUint::one())
+}
+
+/// Converts any trailing 1 bits to 0.
+/// Returns the input if it has no trailing 1 bits.
+pub fn binary_turn_off_trailing_ones<const LIMBS: usize>(x: BigUint<LIMBS>) -> BigUint<LIMBS> {
+    x & x.wrapping_add(BigUint::one())
+}
+
+/// Converts any trailing 0 bits to 1.
+/// Returns the input if it has no trailing 0 bits.
+pub fn binary_turn_on_trailing_zeros<const LIMBS: usize>(x: BigUint<LIMBS>) -> BigUint<LIMBS> {
+    x | x.wrapping_sub(BigUint::one())
+}
+
+/// Generates the bitmask identifying the rightmost 0 bit.
+/// Returns 0 if there is no rightmost 0 bit.
+pub fn binary_rightmost_zero_bitmask<const LIMBS: usize>(x: BigUint<LIMBS>) -> BigUint<LIMBS> {
+    !x & x.wrapping_add(BigUint::one())
+}
+
+/// Generates the bitmask identifying the rightmost 1 bit.
+/// Returns 0 if there is no rightmost 1 bit.
+pub fn binary_rightmost_one_bitmask<const LIMBS: usize>(x: BigUint<LIMBS>) -> BigUint<LIMBS> {
+    x & x.wrapping_neg()
+}
+
+/// Generates the bitmask identifying any trailing 0 bits.
+/// Returns 0 if there are no trailing 0 bits.
+pub fn binary_trailing_zeros_bitmask<const LIMBS: usize>(x: BigUint<LIMBS>) -> BigUint<LIMBS> {
+    !x & x.wrapping_sub(BigUint::one())
+}
+
+/// Generates the bitmask identifying any trailing 1 bits.
+/// Returns 0 if there are no trailing 1 bits.
+pub fn binary_trailing_ones_bitmask<const LIMBS: usize>(x: BigUint<LIMBS>) -> BigUint<LIMBS> {
+    x & !x.wrapping_add(BigUint::one())
+}
+
+/// Generates the bitmask with the rightmost contiguous run
+/// of 1 bits disabled.
+/// Returns 0 if there is at most one contiguous run of 1 bits.
+pub fn binary_leading_ones_bitmask<const LIMBS: usize>(x: BigUint<LIMBS>) -> BigUint<LIMBS> {
+    let rightmost = binary_rightmost_one_bitmask(x);
+
+    rightmost.wrapping_add(x) & x
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const MAX: usize = usize::MAX;
+
+    #[test]
+    fn test_binary_turn_off_rightmost_one() {
+        // The only set bit lives in the high limb; turning it off borrows all
+        // the way down and yields zero.
+        let x = BigUint([0, 1]);
+        let y = binary_turn_off_rightmost_one(x);
+
+        assert_eq!(y, BigUint([0, 0]));
+    }
+
+    #[test]
+    fn test_binary_turn_on_rightmost_zero() {
+        let x = BigUint([MAX, 0]);
+        let y = binary_turn_on_rightmost_zero(x);
+
+        assert_eq!(y, BigUint([MAX, 1]));
+    }
+
+    #[test]
+    fn test_binary_turn_off_trailing_ones() {
+        let x = BigUint([MAX, 0]);
+        let y = binary_turn_off_trailing_ones(x);
+
+        assert_eq!(y, BigUint([0, 0]));
+    }
+
+    #[test]
+    fn test_binary_turn_on_trailing_zeros() {
+        let x = BigUint([0, 1]);
+        let y = binary_turn_on_trailing_zeros(x);
+
+        assert_eq!(y, BigUint([MAX, 1]));
+    }
+
+    #[test]
+    fn test_binary_rightmost_zero_bitmask() {
+        let x = BigUint([MAX, 0]);
+        let y = binary_rightmost_zero_bitmask(x);
+
+        assert_eq!(y, BigUint([0, 1]));
+    }
+
+    #[test]
+    fn test_binary_rightmost_one_bitmask() {
+        let x = BigUint([0, 0b11000]);
+        let y = binary_rightmost_one_bitmask(x);
+
+        assert_eq!(y, BigUint([0, 0b01000]));
+    }
+
+    #[test]
+    fn test_binary_trailing_zeros_bitmask() {
+        let x = BigUint([0, 1]);
+        let y = binary_trailing_zeros_bitmask(x);
+
+        assert_eq!(y, BigUint([MAX, 0]));
+    }
+
+    #[test]
+    fn test_binary_trailing_ones_bitmask() {
+        let x = BigUint([MAX, 0]);
+        let y = binary_trailing_ones_bitmask(x);
+
+        assert_eq!(y, BigUint([MAX, 0]));
+    }
+
+    #[test]
+    fn test_binary_leading_ones_bitmask() {
+        // Single-limb case matches the crate-root doctest.
+        let x = BigUint([0b11011110]);
+        let y = binary_leading_ones_bitmask(x);
+
+        assert_eq!(y, BigUint([0b11000000]));
+    }
+
+    #[test]
+    fn test_all_zero_and_all_ones_edges() {
+        let zero = BigUint::<2>::ZERO;
+        let ones = BigUint([MAX, MAX]);
+
+        assert_eq!(binary_turn_off_rightmost_one(zero), zero);
+        assert_eq!(binary_turn_on_rightmost_zero(ones), ones);
+        assert_eq!(binary_rightmost_one_bitmask(zero), zero);
+    }
+}
@@ -5,9 +5,170 @@
 // efficient machine code, but maintains a safe interface.
 use std::num::Wrapping;
 
+use core::ops::{BitAnd, BitOr, Not};
 
-const ZERO: Wrapping<usize> = Wrapping(0 as usize);
-const ONE: Wrapping<usize> = Wrapping(1 as usize);
+#[cfg(feature = "num-traits")]
+pub mod num;
+
+pub mod big;
+
+/// A fixed-width block of bits over which the delight routines operate.
+///
+/// Implementors behave like the primitive unsigned integers: two's-complement
+/// values `BITS` bits wide whose arithmetic wraps at the block boundary. The
+/// `wrapping_add`/`wrapping_sub` hooks stand in for the `Wrapping` arithmetic
+/// the routines rely on so a single generic implementation serves every width.
+///
+/// # Safety
+///
+/// Implementors must uphold the following invariants; the routines below assume
+/// them and would produce nonsense for a type that violates them:
+///
+/// * `ZERO` is the all-zero bit pattern, i.e. `ZERO == core::mem::zeroed()`.
+/// * `ONE` is `ZERO` with only the least-significant bit set.
+/// * `BITS` is the number of value bits and is a power of two, i.e.
+///   `2usize.pow(BITS.trailing_zeros()) == BITS as usize`.
+/// * `wrapping_add`/`wrapping_sub` compute modular `+`/`-` over those `BITS`
+///   bits, matching `Not`/`BitAnd`/`BitOr` acting on the same bit positions.
+pub unsafe trait BitBlock:
+    Copy + Not<Output = Self> + BitAnd<Output = Self> + BitOr<Output = Self> + Ord
+{
+    /// The all-zero bit pattern.
+    const ZERO: Self;
+    /// `ZERO` with only the least-significant bit set.
+    const ONE: Self;
+    /// The number of value bits in the block.
+    const BITS: u32;
+
+    /// Wrapping (modular) addition, mirroring `Wrapping::add`.
+    fn wrapping_add(self, rhs: Self) -> Self;
+    /// Wrapping (modular) subtraction, mirroring `Wrapping::sub`.
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    /// Addition returning the wrapped result and whether it overflowed,
+    /// mirroring the primitive `overflowing_add`.
+    fn overflowing_add(self, rhs: Self) -> (Self, bool);
+    /// Subtraction returning the wrapped result and whether it overflowed,
+    /// mirroring the primitive `overflowing_sub`.
+    fn overflowing_sub(self, rhs: Self) -> (Self, bool);
+}
+
+macro_rules! impl_bitblock {
+    ($($t:ty),+ $(,)?) => {$(
+        // SAFETY: the primitive unsigned integers are two's-complement blocks
+        // `<$t>::BITS` wide (a power of two) whose zero value is the all-zero
+        // pattern; the wrapping hooks delegate to `Wrapping`, the same modular
+        // arithmetic the operator impls use.
+        unsafe impl BitBlock for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const BITS: u32 = <$t>::BITS;
+
+            fn wrapping_add(self, rhs: Self) -> Self {
+                (Wrapping(self) + Wrapping(rhs)).0
+            }
+
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                (Wrapping(self) - Wrapping(rhs)).0
+            }
+
+            fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                <$t>::overflowing_add(self, rhs)
+            }
+
+            fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                <$t>::overflowing_sub(self, rhs)
+            }
+        }
+    )+};
+}
+
+impl_bitblock!(u8, u16, u32, u64, u128, usize);
+
+/// Converts the right-most 1 bit to a 0, also reporting whether the underlying
+/// `- 1` overflowed. The flag is `true` only for an all-zero input, where there
+/// is no rightmost 1 bit to clear.
+///
+/// ```
+/// # use delight::binary_turn_off_rightmost_one_overflowing;
+/// assert_eq!(binary_turn_off_rightmost_one_overflowing(0usize), (0, true));
+/// ```
+pub fn binary_turn_off_rightmost_one_overflowing<B: BitBlock>(x: B) -> (B, bool) {
+    let (m, overflow) = x.overflowing_sub(B::ONE);
+
+    (x & m, overflow)
+}
+
+/// Converts the right-most 0 bit to a 1, also reporting whether the underlying
+/// `+ 1` overflowed. The flag is `true` only for an all-ones input, where there
+/// is no rightmost 0 bit to set.
+///
+/// ```
+/// # use delight::binary_turn_on_rightmost_zero_overflowing;
+/// assert_eq!(binary_turn_on_rightmost_zero_overflowing(usize::MAX), (usize::MAX, true));
+/// ```
+pub fn binary_turn_on_rightmost_zero_overflowing<B: BitBlock>(x: B) -> (B, bool) {
+    let (m, overflow) = x.overflowing_add(B::ONE);
+
+    (x | m, overflow)
+}
+
+/// Converts any trailing 1 bits to 0, also reporting whether the underlying
+/// `+ 1` overflowed (an all-ones input).
+pub fn binary_turn_off_trailing_ones_overflowing<B: BitBlock>(x: B) -> (B, bool) {
+    let (m, overflow) = x.overflowing_add(B::ONE);
+
+    (x & m, overflow)
+}
+
+/// Converts any trailing 0 bits to 1, also reporting whether the underlying
+/// `- 1` overflowed (an all-zero input).
+pub fn binary_turn_on_trailing_zeros_overflowing<B: BitBlock>(x: B) -> (B, bool) {
+    let (m, overflow) = x.overflowing_sub(B::ONE);
+
+    (x | m, overflow)
+}
+
+/// Generates the bitmask identifying the rightmost 0 bit, also reporting
+/// whether the underlying `+ 1` overflowed (an all-ones input).
+pub fn binary_rightmost_zero_bitmask_overflowing<B: BitBlock>(x: B) -> (B, bool) {
+    let (m, overflow) = x.overflowing_add(B::ONE);
+
+    (!x & m, overflow)
+}
+
+/// Generates the bitmask identifying the rightmost 1 bit, also reporting
+/// whether the underlying two's-complement negation overflowed (any non-zero
+/// input).
+pub fn binary_rightmost_one_bitmask_overflowing<B: BitBlock>(x: B) -> (B, bool) {
+    let (m, overflow) = B::ZERO.overflowing_sub(x);
+
+    (x & m, overflow)
+}
+
+/// Generates the bitmask identifying any trailing 0 bits, also reporting
+/// whether the underlying `- 1` overflowed (an all-zero input).
+pub fn binary_trailing_zeros_bitmask_overflowing<B: BitBlock>(x: B) -> (B, bool) {
+    let (m, overflow) = x.overflowing_sub(B::ONE);
+
+    (!x & m, overflow)
+}
+
+/// Generates the bitmask identifying any trailing 1 bits, also reporting
+/// whether the underlying `+ 1` overflowed (an all-ones input).
+pub fn binary_trailing_ones_bitmask_overflowing<B: BitBlock>(x: B) -> (B, bool) {
+    let (m, overflow) = x.overflowing_add(B::ONE);
+
+    (x & !m, overflow)
+}
+
+/// Generates the bitmask with the rightmost contiguous run of 1 bits disabled,
+/// also reporting whether the underlying addition overflowed.
+pub fn binary_leading_ones_bitmask_overflowing<B: BitBlock>(x: B) -> (B, bool) {
+    let rightmost = binary_rightmost_one_bitmask(x);
+    let (m, overflow) = rightmost.overflowing_add(x);
+
+    (m & x, overflow)
+}
 
 /// Converts the right-most 1 bit to a 0.
 /// Returns 0 if all bits are 0.
@@ -19,10 +180,8 @@ const ONE: Wrapping<usize> = Wrapping(1 as usize);
 ///
 /// assert_eq!(format!("{:08b}", y), "11001100");
 /// ```
-pub fn binary_turn_off_rightmost_one(x: usize) -> usize {
-    let w = Wrapping(x);
-
-    x & (w - ONE).0
+pub fn binary_turn_off_rightmost_one<B: BitBlock>(x: B) -> B {
+    binary_turn_off_rightmost_one_overflowing(x).0
 }
 
 /// Converts the right-most 0 bit to a 1.
@@ -35,10 +194,8 @@ pub fn binary_turn_off_rightmost_one(x: usize) -> usize {
 ///
 /// assert_eq!(format!("{:08b}", y), "11011111");
 /// ```
-pub fn binary_turn_on_rightmost_zero(x: usize) -> usize {
-    let w = Wrapping(x);
-
-    x | (w + ONE).0
+pub fn binary_turn_on_rightmost_zero<B: BitBlock>(x: B) -> B {
+    binary_turn_on_rightmost_zero_overflowing(x).0
 }
 
 /// Converts any trailing 1 bits to 0.
@@ -51,10 +208,8 @@ pub fn binary_turn_on_rightmost_zero(x: usize) -> usize {
 ///
 /// assert_eq!(format!("{:08b}", y), "11011000");
 /// ```
-pub fn binary_turn_off_trailing_ones(x: usize) -> usize {
-    let w = Wrapping(x);
-
-    x & (w + ONE).0
+pub fn binary_turn_off_trailing_ones<B: BitBlock>(x: B) -> B {
+    binary_turn_off_trailing_ones_overflowing(x).0
 }
 
 /// Converts any trailing 0 bits to 1.
@@ -67,10 +222,8 @@ pub fn binary_turn_off_trailing_ones(x: usize) -> usize {
 ///
 /// assert_eq!(format!("{:08b}", y), "11011111");
 /// ```
-pub fn binary_turn_on_trailing_zeros(x: usize) -> usize {
-    let w = Wrapping(x);
-
-    x | (w - ONE).0
+pub fn binary_turn_on_trailing_zeros<B: BitBlock>(x: B) -> B {
+    binary_turn_on_trailing_zeros_overflowing(x).0
 }
 
 /// Generates the bitmask identifying the rightmost 0 bit.
@@ -80,13 +233,11 @@ pub fn binary_turn_on_trailing_zeros(x: usize) -> usize {
 /// # use delight::binary_rightmost_zero_bitmask;
 /// let x = usize::from_str_radix("11011011", 2).unwrap();
 /// let y = binary_rightmost_zero_bitmask(x);
-/// 
+///
 /// assert_eq!(format!("{:08b}", y), "00000100");
 /// ```
-pub fn binary_rightmost_zero_bitmask(x: usize) -> usize {
-    let w = Wrapping(x);
-
-    !x & (w + ONE).0
+pub fn binary_rightmost_zero_bitmask<B: BitBlock>(x: B) -> B {
+    binary_rightmost_zero_bitmask_overflowing(x).0
 }
 
 /// Generates the bitmask identifying the rightmost 1 bit.
@@ -96,13 +247,11 @@ pub fn binary_rightmost_zero_bitmask(x: usize) -> usize {
 /// # use delight::binary_rightmost_one_bitmask;
 /// let x = usize::from_str_radix("11011000", 2).unwrap();
 /// let y = binary_rightmost_one_bitmask(x);
-/// 
+///
 /// assert_eq!(format!("{:08b}", y), "00001000");
 /// ```
-pub fn binary_rightmost_one_bitmask(x: usize) -> usize {
-    let w = Wrapping(x);
-
-    x & (ZERO - w).0
+pub fn binary_rightmost_one_bitmask<B: BitBlock>(x: B) -> B {
+    binary_rightmost_one_bitmask_overflowing(x).0
 }
 
 /// Generates the bitmask identifying any trailing 0 bits.
@@ -115,10 +264,8 @@ pub fn binary_rightmost_one_bitmask(x: usize) -> usize {
 ///
 /// assert_eq!(format!("{:08b}", y), "00000111");
 /// ```
-pub fn binary_trailing_zeros_bitmask(x: usize) -> usize {
-    let w = Wrapping(x);
-
-    !x & (w - ONE).0
+pub fn binary_trailing_zeros_bitmask<B: BitBlock>(x: B) -> B {
+    binary_trailing_zeros_bitmask_overflowing(x).0
 }
 
 /// Generates the bitmask identifying any trailing 1 bits.
@@ -131,10 +278,8 @@ pub fn binary_trailing_zeros_bitmask(x: usize) -> usize {
 ///
 /// assert_eq!(format!("{:08b}", y), "00000011");
 /// ```
-pub fn binary_trailing_ones_bitmask(x: usize) -> usize {
-    let w = Wrapping(x);
-
-    x & !(w + ONE).0
+pub fn binary_trailing_ones_bitmask<B: BitBlock>(x: B) -> B {
+    binary_trailing_ones_bitmask_overflowing(x).0
 }
 
 /// Generates the bitmask with the leftmost contiguous run
@@ -142,18 +287,115 @@ pub fn binary_trailing_ones_bitmask(x: usize) -> usize {
 /// Returns 0 if there are no trailing 1 bits.
 ///
 /// ```
-/// # use delight::binary_trailing_ones_bitmask;
+/// # use delight::binary_leading_ones_bitmask;
 /// let x = usize::from_str_radix("11011110", 2).unwrap();
 /// let y = binary_leading_ones_bitmask(x);
 ///
 /// assert_eq!(format!("{:08b}", y), "11000000");
 /// ```
-pub fn binary_leading_ones_bitmask(x: usize) -> usize {
-    let w = Wrapping(x);
+pub fn binary_leading_ones_bitmask<B: BitBlock>(x: B) -> B {
+    binary_leading_ones_bitmask_overflowing(x).0
+}
 
-    let rightmost = Wrapping(binary_rightmost_one_bitmask(x));
+/// Returns the mask of the low `bits` significant positions.
+///
+/// `bits == 0` yields an empty mask and `bits >= usize::BITS` yields the full
+/// `usize` mask, so callers never shift by the word width.
+fn width_mask(bits: u32) -> usize {
+    if bits == 0 {
+        0
+    } else if bits >= usize::BITS {
+        usize::MAX
+    } else {
+        (1 << bits) - 1
+    }
+}
 
-    ((rightmost + w) & w).0
+/// Converts the right-most 1 bit to a 0, treating only the low `bits`
+/// positions as significant.
+pub fn binary_turn_off_rightmost_one_bits(x: usize, bits: u32) -> usize {
+    let mask = width_mask(bits);
+
+    binary_turn_off_rightmost_one(x & mask) & mask
+}
+
+/// Converts the right-most 0 bit to a 1, treating only the low `bits`
+/// positions as significant. A window that is entirely 1 bits is left
+/// unchanged.
+pub fn binary_turn_on_rightmost_zero_bits(x: usize, bits: u32) -> usize {
+    let mask = width_mask(bits);
+
+    binary_turn_on_rightmost_zero(x & mask) & mask
+}
+
+/// Converts any trailing 1 bits to 0, treating only the low `bits`
+/// positions as significant.
+pub fn binary_turn_off_trailing_ones_bits(x: usize, bits: u32) -> usize {
+    let mask = width_mask(bits);
+
+    binary_turn_off_trailing_ones(x & mask) & mask
+}
+
+/// Converts any trailing 0 bits to 1, treating only the low `bits`
+/// positions as significant.
+pub fn binary_turn_on_trailing_zeros_bits(x: usize, bits: u32) -> usize {
+    let mask = width_mask(bits);
+
+    binary_turn_on_trailing_zeros(x & mask) & mask
+}
+
+/// Generates the bitmask identifying the rightmost 0 bit, treating only the
+/// low `bits` positions as significant.
+pub fn binary_rightmost_zero_bitmask_bits(x: usize, bits: u32) -> usize {
+    let mask = width_mask(bits);
+
+    binary_rightmost_zero_bitmask(x & mask) & mask
+}
+
+/// Generates the bitmask identifying the rightmost 1 bit, treating only the
+/// low `bits` positions as significant.
+pub fn binary_rightmost_one_bitmask_bits(x: usize, bits: u32) -> usize {
+    let mask = width_mask(bits);
+
+    binary_rightmost_one_bitmask(x & mask) & mask
+}
+
+/// Generates the bitmask identifying any trailing 0 bits, treating only the
+/// low `bits` positions as significant.
+pub fn binary_trailing_zeros_bitmask_bits(x: usize, bits: u32) -> usize {
+    let mask = width_mask(bits);
+
+    binary_trailing_zeros_bitmask(x & mask) & mask
+}
+
+/// Generates the bitmask identifying any trailing 1 bits, treating only the
+/// low `bits` positions as significant.
+pub fn binary_trailing_ones_bitmask_bits(x: usize, bits: u32) -> usize {
+    let mask = width_mask(bits);
+
+    binary_trailing_ones_bitmask(x & mask) & mask
+}
+
+/// Generates the leading-ones bitmask within a `bits`-wide window.
+///
+/// The input is clamped to the low `bits` positions before
+/// [`binary_leading_ones_bitmask`] is applied, so set bits at and above
+/// position `bits` neither appear in the result nor affect it:
+/// `bits == usize::BITS` falls back to the full-width behavior and `bits == 0`
+/// returns 0.
+///
+/// ```
+/// # use delight::binary_leading_ones_bitmask_bits;
+/// // Bits at and above position 8 are phantom and ignored.
+/// let x = usize::from_str_radix("111011110", 2).unwrap();
+/// let y = binary_leading_ones_bitmask_bits(x, 8);
+///
+/// assert_eq!(format!("{:08b}", y), "11000000");
+/// ```
+pub fn binary_leading_ones_bitmask_bits(x: usize, bits: u32) -> usize {
+    let mask = width_mask(bits);
+
+    binary_leading_ones_bitmask(x & mask) & mask
 }
 
 #[cfg(test)]
@@ -232,4 +474,163 @@ mod tests {
 
         assert_eq!(format!("{:08b}", y), "11000000");
     }
+
+    #[test]
+    fn test_binary_turn_off_rightmost_one_overflowing() {
+        assert_eq!(binary_turn_off_rightmost_one_overflowing(0usize), (0, true));
+
+        let x = usize::from_str_radix("11001110", 2).unwrap();
+        assert!(!binary_turn_off_rightmost_one_overflowing(x).1);
+    }
+
+    #[test]
+    fn test_binary_turn_on_rightmost_zero_overflowing() {
+        assert_eq!(
+            binary_turn_on_rightmost_zero_overflowing(usize::MAX),
+            (usize::MAX, true)
+        );
+
+        let x = usize::from_str_radix("11001111", 2).unwrap();
+        assert!(!binary_turn_on_rightmost_zero_overflowing(x).1);
+    }
+
+    #[test]
+    fn test_binary_turn_off_trailing_ones_overflowing() {
+        assert_eq!(
+            binary_turn_off_trailing_ones_overflowing(usize::MAX),
+            (0, true)
+        );
+    }
+
+    #[test]
+    fn test_binary_turn_on_trailing_zeros_overflowing() {
+        assert_eq!(
+            binary_turn_on_trailing_zeros_overflowing(0usize),
+            (usize::MAX, true)
+        );
+    }
+
+    #[test]
+    fn test_binary_rightmost_zero_bitmask_overflowing() {
+        assert_eq!(
+            binary_rightmost_zero_bitmask_overflowing(usize::MAX),
+            (0, true)
+        );
+    }
+
+    #[test]
+    fn test_binary_rightmost_one_bitmask_overflowing() {
+        assert_eq!(binary_rightmost_one_bitmask_overflowing(0usize), (0, false));
+
+        let x = usize::from_str_radix("11011000", 2).unwrap();
+        let (y, overflow) = binary_rightmost_one_bitmask_overflowing(x);
+        assert_eq!(format!("{:08b}", y), "00001000");
+        assert!(overflow);
+    }
+
+    #[test]
+    fn test_binary_trailing_zeros_bitmask_overflowing() {
+        assert_eq!(
+            binary_trailing_zeros_bitmask_overflowing(0usize),
+            (usize::MAX, true)
+        );
+    }
+
+    #[test]
+    fn test_binary_trailing_ones_bitmask_overflowing() {
+        assert_eq!(
+            binary_trailing_ones_bitmask_overflowing(usize::MAX),
+            (usize::MAX, true)
+        );
+    }
+
+    #[test]
+    fn test_binary_leading_ones_bitmask_overflowing() {
+        let x = usize::from_str_radix("11011110", 2).unwrap();
+        let (y, _) = binary_leading_ones_bitmask_overflowing(x);
+
+        assert_eq!(format!("{:08b}", y), "11000000");
+    }
+
+    #[test]
+    fn test_binary_turn_off_rightmost_one_bits() {
+        let x = usize::from_str_radix("111001110", 2).unwrap();
+        let y = binary_turn_off_rightmost_one_bits(x, 8);
+
+        assert_eq!(format!("{:08b}", y), "11001100");
+    }
+
+    #[test]
+    fn test_binary_turn_on_rightmost_zero_bits() {
+        let x = usize::from_str_radix("111001111", 2).unwrap();
+        let y = binary_turn_on_rightmost_zero_bits(x, 8);
+
+        assert_eq!(format!("{:08b}", y), "11011111");
+
+        // A window that is entirely 1 bits has no rightmost 0 to turn on.
+        assert_eq!(binary_turn_on_rightmost_zero_bits(0xFF, 8), 0xFF);
+    }
+
+    #[test]
+    fn test_binary_turn_off_trailing_ones_bits() {
+        let x = usize::from_str_radix("111011011", 2).unwrap();
+        let y = binary_turn_off_trailing_ones_bits(x, 8);
+
+        assert_eq!(format!("{:08b}", y), "11011000");
+    }
+
+    #[test]
+    fn test_binary_turn_on_trailing_zeros_bits() {
+        let x = usize::from_str_radix("111011000", 2).unwrap();
+        let y = binary_turn_on_trailing_zeros_bits(x, 8);
+
+        assert_eq!(format!("{:08b}", y), "11011111");
+    }
+
+    #[test]
+    fn test_binary_rightmost_zero_bitmask_bits() {
+        let x = usize::from_str_radix("111011011", 2).unwrap();
+        let y = binary_rightmost_zero_bitmask_bits(x, 8);
+
+        assert_eq!(format!("{:08b}", y), "00000100");
+    }
+
+    #[test]
+    fn test_binary_rightmost_one_bitmask_bits() {
+        let x = usize::from_str_radix("111011000", 2).unwrap();
+        let y = binary_rightmost_one_bitmask_bits(x, 8);
+
+        assert_eq!(format!("{:08b}", y), "00001000");
+    }
+
+    #[test]
+    fn test_binary_trailing_zeros_bitmask_bits() {
+        let x = usize::from_str_radix("111011000", 2).unwrap();
+        let y = binary_trailing_zeros_bitmask_bits(x, 8);
+
+        assert_eq!(format!("{:08b}", y), "00000111");
+    }
+
+    #[test]
+    fn test_binary_trailing_ones_bitmask_bits() {
+        let x = usize::from_str_radix("111011011", 2).unwrap();
+        let y = binary_trailing_ones_bitmask_bits(x, 8);
+
+        assert_eq!(format!("{:08b}", y), "00000011");
+    }
+
+    #[test]
+    fn test_binary_leading_ones_bitmask_bits() {
+        let x = usize::from_str_radix("111011110", 2).unwrap();
+        let y = binary_leading_ones_bitmask_bits(x, 8);
+
+        assert_eq!(format!("{:08b}", y), "11000000");
+
+        // The degenerate widths behave as documented.
+        assert_eq!(binary_leading_ones_bitmask_bits(x, 0), 0);
+        assert_eq!(
+            binary_leading_ones_bitmask_bits(x, usize::BITS),
+            binary_leading_ones_bitmask(x)
+        );
+    }
 }
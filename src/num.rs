@@ -0,0 +1,148 @@
+//! `num-traits`-based variants of the bit-twiddling routines.
+//!
+//! The core crate carries its own [`BitBlock`](crate::BitBlock) trait so it
+//! stays dependency-free, but that trait only covers the primitive integers we
+//! add impls for. This module expresses the same bounds with the `num-traits`
+//! vocabulary (`PrimInt`, `WrappingAdd`, `WrappingSub`, `Zero`, `One`) so the
+//! routines drop straight into existing generic numeric code and apply to any
+//! third-party integer type implementing those traits.
+//!
+//! It is gated behind the off-by-default `num-traits` feature and is
+//! `no_std`-friendly.
+
+use num_traits::{PrimInt, WrappingAdd, WrappingSub};
+
+/// Converts the right-most 1 bit to a 0.
+/// Returns 0 if all bits are 0.
+pub fn binary_turn_off_rightmost_one<T: PrimInt + WrappingSub>(x: T) -> T {
+    x & x.wrapping_sub(&T::one())
+}
+
+/// Converts the right-most 0 bit to a 1.
+/// Returns the all-ones value if all bits are 1.
+pub fn binary_turn_on_rightmost_zero<T: PrimInt + WrappingAdd>(x: T) -> T {
+    x | x.wrapping_add(&T::one())
+}
+
+/// Converts any trailing 1 bits to 0.
+/// Returns the input if it has no trailing 1 bits.
+pub fn binary_turn_off_trailing_ones<T: PrimInt + WrappingAdd>(x: T) -> T {
+    x & x.wrapping_add(&T::one())
+}
+
+/// Converts any trailing 0 bits to 1.
+/// Returns the input if it has no trailing 0 bits.
+pub fn binary_turn_on_trailing_zeros<T: PrimInt + WrappingSub>(x: T) -> T {
+    x | x.wrapping_sub(&T::one())
+}
+
+/// Generates the bitmask identifying the rightmost 0 bit.
+/// Returns 0 if there is no rightmost 0 bit.
+pub fn binary_rightmost_zero_bitmask<T: PrimInt + WrappingAdd>(x: T) -> T {
+    !x & x.wrapping_add(&T::one())
+}
+
+/// Generates the bitmask identifying the rightmost 1 bit.
+/// Returns 0 if there is no rightmost 1 bit.
+pub fn binary_rightmost_one_bitmask<T: PrimInt + WrappingSub>(x: T) -> T {
+    x & T::zero().wrapping_sub(&x)
+}
+
+/// Generates the bitmask identifying any trailing 0 bits.
+/// Returns 0 if there are no trailing 0 bits.
+pub fn binary_trailing_zeros_bitmask<T: PrimInt + WrappingSub>(x: T) -> T {
+    !x & x.wrapping_sub(&T::one())
+}
+
+/// Generates the bitmask identifying any trailing 1 bits.
+/// Returns 0 if there are no trailing 1 bits.
+pub fn binary_trailing_ones_bitmask<T: PrimInt + WrappingAdd>(x: T) -> T {
+    x & !x.wrapping_add(&T::one())
+}
+
+/// Generates the bitmask with the rightmost contiguous run
+/// of 1 bits disabled.
+/// Returns 0 if there is at most one contiguous run of 1 bits.
+pub fn binary_leading_ones_bitmask<T: PrimInt + WrappingAdd + WrappingSub>(x: T) -> T {
+    let rightmost = binary_rightmost_one_bitmask(x);
+
+    rightmost.wrapping_add(&x) & x
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_binary_turn_off_rightmost_one() {
+        let x = usize::from_str_radix("11001110", 2).unwrap();
+        let y = binary_turn_off_rightmost_one(x);
+
+        assert_eq!(format!("{:08b}", y), "11001100");
+    }
+
+    #[test]
+    fn test_binary_turn_on_rightmost_zero() {
+        let x = usize::from_str_radix("11001111", 2).unwrap();
+        let y = binary_turn_on_rightmost_zero(x);
+
+        assert_eq!(format!("{:08b}", y), "11011111");
+    }
+
+    #[test]
+    fn test_binary_turn_off_trailing_ones() {
+        let x = usize::from_str_radix("11011011", 2).unwrap();
+        let y = binary_turn_off_trailing_ones(x);
+
+        assert_eq!(format!("{:08b}", y), "11011000");
+    }
+
+    #[test]
+    fn test_binary_turn_on_trailing_zeros() {
+        let x = usize::from_str_radix("11011000", 2).unwrap();
+        let y = binary_turn_on_trailing_zeros(x);
+
+        assert_eq!(format!("{:08b}", y), "11011111");
+    }
+
+    #[test]
+    fn test_binary_rightmost_zero_bitmask() {
+        let x = usize::from_str_radix("11011011", 2).unwrap();
+        let y = binary_rightmost_zero_bitmask(x);
+
+        assert_eq!(format!("{:08b}", y), "00000100");
+    }
+
+    #[test]
+    fn test_binary_rightmost_one_bitmask() {
+        let x = usize::from_str_radix("11011000", 2).unwrap();
+        let y = binary_rightmost_one_bitmask(x);
+
+        assert_eq!(format!("{:08b}", y), "00001000");
+    }
+
+    #[test]
+    fn test_binary_trailing_zeros_bitmask() {
+        let x = usize::from_str_radix("11011000", 2).unwrap();
+        let y = binary_trailing_zeros_bitmask(x);
+
+        assert_eq!(format!("{:08b}", y), "00000111");
+    }
+
+    #[test]
+    fn test_binary_trailing_ones_bitmask() {
+        let x = usize::from_str_radix("11011011", 2).unwrap();
+        let y = binary_trailing_ones_bitmask(x);
+
+        assert_eq!(format!("{:08b}", y), "00000011");
+    }
+
+    #[test]
+    fn test_binary_leading_ones_bitmask() {
+        let x = usize::from_str_radix("11011110", 2).unwrap();
+        let y = binary_leading_ones_bitmask(x);
+
+        assert_eq!(format!("{:08b}", y), "11000000");
+    }
+}